@@ -4,9 +4,12 @@
 
 use std::fs;
 use std::fs::metadata;
+use std::path::Path;
 
 use cloudflare::framework::response::ApiFailure;
+use serde::Serialize;
 use url::Url;
+use walkdir::WalkDir;
 
 use crate::commands::kv;
 use crate::http;
@@ -17,6 +20,12 @@ use reqwest::blocking::multipart;
 use reqwest::blocking::Body;
 use regex::Regex;
 
+// The bulk endpoint rejects a request outright if it holds more than 10,000
+// pairs, or roughly 100 MB of serialized JSON, whichever limit is hit first.
+// https://api.cloudflare.com/#workers-kv-namespace-write-multiple-key-value-pairs
+const KV_BULK_MAX_PAIRS: usize = 10_000;
+const KV_BULK_MAX_BYTES: usize = 100 * 1024 * 1024;
+
 pub struct KVMetaData {
     pub namespace_id: String,
     pub key: String,
@@ -25,6 +34,12 @@ pub struct KVMetaData {
     pub expiration: Option<String>,
     pub expiration_ttl: Option<String>,
     pub metadata: Option<serde_json::Value>,
+    // Set via --base64 when the (inline) value is a base64-encoded binary
+    // blob rather than plain text.
+    pub base64: bool,
+    // Set via --metadata-file; when present, `put` streams this path into
+    // the multipart form instead of re-serializing `metadata`.
+    pub metadata_path: Option<String>,
 }
 
 pub fn parse_metadata(arg: Option<&str>) -> Result<Option<serde_json::Value>, failure::Error> {
@@ -46,8 +61,81 @@ pub fn parse_metadata(arg: Option<&str>) -> Result<Option<serde_json::Value>, fa
     }
 }
 
+/// Like `parse_metadata`, but reads the JSON from disk -- for metadata too
+/// large or too generated to paste inline with `--metadata`.
+pub fn parse_metadata_file(path: &str) -> Result<Option<serde_json::Value>, failure::Error> {
+    parse_metadata(Some(&fs::read_to_string(path)?))
+}
+
+/// Caps enforced by the Workers KV service itself. Checking these client-side
+/// lets us fail with an actionable message instead of letting the user
+/// discover the limit from an opaque API 4xx after uploading a huge file.
+struct KvLimits {
+    max_key_bytes: usize,
+    max_value_bytes: usize,
+    max_metadata_bytes: usize,
+}
+
+impl Default for KvLimits {
+    fn default() -> KvLimits {
+        KvLimits {
+            max_key_bytes: 512,
+            max_value_bytes: 25 * 1024 * 1024,
+            max_metadata_bytes: 1024,
+        }
+    }
+}
+
+impl KvLimits {
+    fn validate(&self, data: &KVMetaData) -> Result<(), failure::Error> {
+        if data.key.len() > self.max_key_bytes {
+            failure::bail!(
+                "key \"{}\" is {} bytes, exceeds the {} byte KV key limit",
+                data.key,
+                data.key.len(),
+                self.max_key_bytes
+            );
+        }
+
+        let value_bytes = if data.is_file {
+            metadata(&data.value)?.len() as usize
+        } else if data.base64 {
+            // The KV limit applies to the decoded payload, not the
+            // base64-encoded string sitting on the CLI, which runs ~33%
+            // larger.
+            data.value.len() / 4 * 3
+        } else {
+            data.value.len()
+        };
+        if value_bytes > self.max_value_bytes {
+            // Round up so a value just over the limit is never reported as
+            // the same MiB figure as the limit itself.
+            let value_mib = (value_bytes + 1024 * 1024 - 1) / (1024 * 1024);
+            failure::bail!(
+                "value is {} MiB, exceeds the {} MiB KV limit",
+                value_mib,
+                self.max_value_bytes / (1024 * 1024)
+            );
+        }
+
+        if let Some(metadata) = &data.metadata {
+            let metadata_bytes = metadata.to_string().len();
+            if metadata_bytes > self.max_metadata_bytes {
+                failure::bail!(
+                    "metadata is {} bytes, exceeds the {} byte KV metadata limit",
+                    metadata_bytes,
+                    self.max_metadata_bytes
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
 pub fn put(target: &Target, user: &GlobalUser, data: KVMetaData) -> Result<(), failure::Error> {
     kv::validate_target(target)?;
+    KvLimits::default().validate(&data)?;
 
     let api_endpoint = format!(
         "https://api.cloudflare.com/client/v4/accounts/{}/storage/kv/namespaces/{}/values/{}",
@@ -86,23 +174,279 @@ pub fn put(target: &Target, user: &GlobalUser, data: KVMetaData) -> Result<(), f
     Ok(())
 }
 
+/// One entry in a bulk-write request body, matching the shape the
+/// `/storage/kv/namespaces/{id}/bulk` endpoint expects.
+#[derive(Serialize)]
+struct KeyValuePair {
+    key: String,
+    value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expiration: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expiration_ttl: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "is_false")]
+    base64: bool,
+}
+
+fn is_false(b: &bool) -> bool {
+    !*b
+}
+
+impl KeyValuePair {
+    fn from_kv_metadata(data: KVMetaData) -> Result<KeyValuePair, failure::Error> {
+        // The bulk API understands `base64` itself, so a base64-encoded
+        // value is passed through verbatim rather than decoded here. A
+        // file-backed entry is read as raw bytes and base64-encoded rather
+        // than assuming UTF-8, the same as collect_directory_pairs does --
+        // a file has no guarantee its contents are valid text.
+        let (value, base64) = if data.is_file {
+            (base64::encode(fs::read(&data.value)?), true)
+        } else {
+            (data.value, data.base64)
+        };
+
+        Ok(KeyValuePair {
+            key: data.key,
+            value,
+            expiration: data.expiration,
+            expiration_ttl: data.expiration_ttl,
+            metadata: data.metadata,
+            base64,
+        })
+    }
+}
+
+/// Writes many key-value pairs in as few requests as possible. `put` is
+/// really just the special case of this with a batch size of one, but it
+/// keeps its own fast path above since a single pair can go straight through
+/// as a request body or multipart form instead of being wrapped in JSON.
+pub fn bulk_put(
+    target: &Target,
+    user: &GlobalUser,
+    data: Vec<KVMetaData>,
+) -> Result<(), failure::Error> {
+    kv::validate_target(target)?;
+
+    let namespace_id = match data.first() {
+        Some(first) => first.namespace_id.clone(),
+        None => return Ok(()),
+    };
+
+    let api_endpoint = format!(
+        "https://api.cloudflare.com/client/v4/accounts/{}/storage/kv/namespaces/{}/bulk",
+        target.account_id, namespace_id
+    );
+    let url = Url::parse(&api_endpoint)?;
+    let client = http::legacy_auth_client(user);
+
+    for batch in batch_pairs(data)? {
+        // Report this batch's failure but keep writing the rest; one bad
+        // batch (e.g. a server-side rejection, or a dropped connection)
+        // shouldn't sink an upload of otherwise-good pairs.
+        match client.put(url.as_str()).json(&batch).send() {
+            Ok(res) => {
+                let response_status = res.status();
+                if response_status.is_success() {
+                    StdOut::success("Success")
+                } else {
+                    let parsed = res.json();
+                    let errors = parsed.unwrap_or_default();
+                    print!(
+                        "{}",
+                        kv::format_error(ApiFailure::Error(response_status, errors))
+                    );
+                }
+            }
+            Err(e) => StdOut::warn(&format!("batch failed to send: {}", e)),
+        }
+    }
+
+    Ok(())
+}
+
+/// Greedily packs pairs into batches that stay under the bulk API's
+/// documented per-request limits.
+fn batch_pairs(pairs: Vec<KVMetaData>) -> Result<Vec<Vec<KeyValuePair>>, failure::Error> {
+    batch_pairs_within(pairs, KV_BULK_MAX_PAIRS, KV_BULK_MAX_BYTES)
+}
+
+fn batch_pairs_within(
+    pairs: Vec<KVMetaData>,
+    max_pairs: usize,
+    max_bytes: usize,
+) -> Result<Vec<Vec<KeyValuePair>>, failure::Error> {
+    let limits = KvLimits::default();
+    let mut batches: Vec<Vec<KeyValuePair>> = vec![];
+    let mut current_batch: Vec<KeyValuePair> = vec![];
+    let mut current_batch_bytes = 0;
+
+    for data in pairs {
+        // Reject oversized entries client-side instead of letting them ride
+        // along in a batch that the server will only reject as a whole.
+        if let Err(e) = limits.validate(&data) {
+            StdOut::warn(&format!("skipping key \"{}\": {}", data.key, e));
+            continue;
+        }
+
+        // A single unreadable file (bad path, permissions, ...) shouldn't
+        // sink the rest of an otherwise-good batch.
+        let key = data.key.clone();
+        let pair = match KeyValuePair::from_kv_metadata(data) {
+            Ok(pair) => pair,
+            Err(e) => {
+                StdOut::warn(&format!("skipping key \"{}\": {}", key, e));
+                continue;
+            }
+        };
+        let pair_bytes = serde_json::to_string(&pair)?.len();
+
+        if !current_batch.is_empty()
+            && (current_batch.len() >= max_pairs || current_batch_bytes + pair_bytes > max_bytes)
+        {
+            batches.push(std::mem::take(&mut current_batch));
+            current_batch_bytes = 0;
+        }
+
+        current_batch_bytes += pair_bytes;
+        current_batch.push(pair);
+    }
+
+    if !current_batch.is_empty() {
+        batches.push(current_batch);
+    }
+
+    Ok(batches)
+}
+
+// Sidecar metadata files use this suffix and are skipped as values in their
+// own right when walking a directory.
+const SIDECAR_METADATA_SUFFIX: &str = ".meta.json";
+
+/// Recursively uploads every file under `dir` as a KV pair, keyed by its path
+/// relative to `dir` (optionally joined to `key_prefix`), via `bulk_put`.
+/// Each file may carry its own `<file>.meta.json` sidecar; entries without
+/// one fall back to `metadata`, a single blob shared across the whole import.
+pub fn put_directory(
+    target: &Target,
+    user: &GlobalUser,
+    namespace_id: &str,
+    dir: &Path,
+    key_prefix: Option<&str>,
+    metadata: Option<serde_json::Value>,
+) -> Result<(), failure::Error> {
+    let pairs = collect_directory_pairs(namespace_id, dir, key_prefix, metadata)?;
+    bulk_put(target, user, pairs)
+}
+
+/// Walks `dir`, turning every non-symlink file into a `KVMetaData` pair
+/// keyed by its path relative to `dir` (optionally joined to `key_prefix`).
+fn collect_directory_pairs(
+    namespace_id: &str,
+    dir: &Path,
+    key_prefix: Option<&str>,
+    metadata: Option<serde_json::Value>,
+) -> Result<Vec<KVMetaData>, failure::Error> {
+    let mut pairs = vec![];
+
+    for entry in WalkDir::new(dir) {
+        let entry = entry?;
+        let path = entry.path();
+
+        if entry.file_type().is_symlink() {
+            // Same diagnostic get_request_body gives a --path symlink; just
+            // a warning here since a directory import has many other files
+            // to get through.
+            StdOut::warn(&format!("--path argument takes a file, {} is a symlink", path.display()));
+            continue;
+        }
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        if path.to_string_lossy().ends_with(SIDECAR_METADATA_SUFFIX) {
+            continue;
+        }
+
+        let relative = path.strip_prefix(dir)?.to_string_lossy().replace('\\', "/");
+        let key = match key_prefix {
+            Some(prefix) => format!("{}/{}", prefix, relative),
+            None => relative,
+        };
+
+        let entry_metadata = match sidecar_metadata(path)? {
+            Some(sidecar) => Some(sidecar),
+            None => metadata.clone(),
+        };
+
+        pairs.push(KVMetaData {
+            namespace_id: namespace_id.to_string(),
+            key,
+            // Read as raw bytes and base64-encode rather than assuming
+            // UTF-8; a directory import has no way to know ahead of time
+            // which files are text and which are binary assets.
+            value: base64::encode(fs::read(path)?),
+            is_file: false,
+            expiration: None,
+            expiration_ttl: None,
+            metadata: entry_metadata,
+            base64: true,
+            metadata_path: None,
+        });
+    }
+
+    Ok(pairs)
+}
+
+/// Reads `<file>.meta.json` next to `path`, if it exists.
+fn sidecar_metadata(path: &Path) -> Result<Option<serde_json::Value>, failure::Error> {
+    let mut sidecar = path.as_os_str().to_owned();
+    sidecar.push(SIDECAR_METADATA_SUFFIX);
+    let sidecar = Path::new(&sidecar);
+
+    if !sidecar.is_file() {
+        return Ok(None);
+    }
+
+    Ok(Some(serde_json::from_str(&fs::read_to_string(sidecar)?)?))
+}
+
+// A metadata_path is expected to come with the value parse_metadata_file
+// parsed from that same path, stored in metadata. Bail loudly when only the
+// path is set rather than silently sending the value with no metadata part
+// at all.
+fn check_metadata_source(data: &KVMetaData) -> Result<(), failure::Error> {
+    if data.metadata_path.is_some() && data.metadata.is_none() {
+        failure::bail!("metadata_path was set without a parsed metadata value");
+    }
+    Ok(())
+}
+
 fn get_response(
     data: KVMetaData,
     user: &GlobalUser,
     url: &Url,
 ) -> Result<reqwest::blocking::Response, failure::Error> {
+    check_metadata_source(&data)?;
+
     let url_into_str = url.to_string();
     let client = http::legacy_auth_client(user);
     let res = match data.metadata {
         Some(metadata) => {
             let part = if data.is_file {
                 multipart::Part::file(&data.value)?
+            } else if data.base64 {
+                multipart::Part::bytes(base64::decode(&data.value)?)
             } else {
                 multipart::Part::text(data.value)
             };
+            let metadata_part = match &data.metadata_path {
+                Some(path) => multipart::Part::file(path)?,
+                None => multipart::Part::text(metadata.to_string()),
+            };
             let form = multipart::Form::new()
                 .part("value", part)
-                .text("metadata", metadata.to_string());
+                .part("metadata", metadata_part);
             client.put(&url_into_str).multipart(form).send()?
         }
         None => {
@@ -130,6 +474,8 @@ fn get_request_body(data: KVMetaData) -> Result<Body, failure::Error> {
             Ok(_) => failure::bail!("--path argument takes a file, {} is a symlink", data.value),
             Err(e) => failure::bail!("{}", e),
         }
+    } else if data.base64 {
+        Ok(base64::decode(&data.value)?.into())
     } else {
         Ok(data.value.into())
     }
@@ -174,4 +520,236 @@ mod tests {
         }
         Ok(())
     }
+
+    fn kv_metadata(key: &str, value: &str) -> KVMetaData {
+        KVMetaData {
+            namespace_id: "namespace-id".to_string(),
+            key: key.to_string(),
+            value: value.to_string(),
+            is_file: false,
+            expiration: None,
+            expiration_ttl: None,
+            metadata: None,
+            base64: false,
+            metadata_path: None,
+        }
+    }
+
+    #[test]
+    fn kv_limits_allows_values_within_bounds() {
+        let data = kv_metadata("a-key", "a-value");
+        assert!(KvLimits::default().validate(&data).is_ok());
+    }
+
+    #[test]
+    fn kv_limits_rejects_an_oversized_key() {
+        let data = kv_metadata(&"k".repeat(513), "a-value");
+        assert!(KvLimits::default().validate(&data).is_err());
+    }
+
+    #[test]
+    fn kv_limits_rejects_an_oversized_value() {
+        let data = kv_metadata("a-key", &"v".repeat(26 * 1024 * 1024));
+        assert!(KvLimits::default().validate(&data).is_err());
+    }
+
+    #[test]
+    fn kv_limits_error_message_rounds_up_past_the_limit() {
+        let data = kv_metadata("a-key", &"v".repeat(25 * 1024 * 1024 + 1));
+        let err = KvLimits::default().validate(&data).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "value is 26 MiB, exceeds the 25 MiB KV limit"
+        );
+    }
+
+    #[test]
+    fn kv_limits_measures_base64_values_by_their_decoded_size() {
+        // ~20 MiB of raw bytes, base64-encoded to ~26.7 MiB of text; well
+        // under the 25 MiB decoded-value limit.
+        let mut data = kv_metadata("a-key", &"v".repeat(20 * 1024 * 1024 / 3 * 4));
+        data.base64 = true;
+        assert!(KvLimits::default().validate(&data).is_ok());
+    }
+
+    #[test]
+    fn batch_pairs_skips_entries_that_fail_kv_limits_but_keeps_the_rest() {
+        let oversized = kv_metadata(&"k".repeat(513), "a-value");
+        let ok = kv_metadata("a-key", "a-value");
+
+        let batches = batch_pairs(vec![oversized, ok]).unwrap();
+
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].len(), 1);
+        assert_eq!(batches[0][0].key, "a-key");
+    }
+
+    #[test]
+    fn batch_pairs_splits_once_the_pair_count_limit_is_hit() {
+        let pairs = (0..5)
+            .map(|i| kv_metadata(&format!("key-{}", i), "v"))
+            .collect();
+
+        let batches = batch_pairs_within(pairs, 2, KV_BULK_MAX_BYTES).unwrap();
+
+        assert_eq!(batches.iter().map(Vec::len).collect::<Vec<_>>(), vec![2, 2, 1]);
+    }
+
+    #[test]
+    fn batch_pairs_splits_once_the_byte_size_limit_is_hit() {
+        let pairs = vec![
+            kv_metadata("key-1", "v"),
+            kv_metadata("key-2", "v"),
+            kv_metadata("key-3", "v"),
+        ];
+
+        // Each serialized pair is 27 bytes; cap batches at 60 bytes so only
+        // the first two pairs fit together.
+        let batches = batch_pairs_within(pairs, KV_BULK_MAX_PAIRS, 60).unwrap();
+
+        assert_eq!(batches.iter().map(Vec::len).collect::<Vec<_>>(), vec![2, 1]);
+    }
+
+    #[test]
+    fn kv_limits_rejects_oversized_metadata() {
+        let mut data = kv_metadata("a-key", "a-value");
+        data.metadata = Some(serde_json::json!({ "big": "v".repeat(1024) }));
+        assert!(KvLimits::default().validate(&data).is_err());
+    }
+
+    #[test]
+    fn key_value_pair_passes_base64_values_through_undecoded() {
+        let mut data = kv_metadata("a-key", "c29tZSBieXRlcw==");
+        data.base64 = true;
+        let pair = KeyValuePair::from_kv_metadata(data).unwrap();
+        assert_eq!(pair.value, "c29tZSBieXRlcw==");
+        assert_eq!(
+            serde_json::to_value(&pair).unwrap()["base64"],
+            serde_json::json!(true)
+        );
+    }
+
+    #[test]
+    fn key_value_pair_base64_encodes_file_contents_for_is_file_entries() {
+        let path = std::env::temp_dir().join("wrangler_test_key_value_pair_is_file.bin");
+        let bytes = [0xff, 0xfe, 0x00, 0x01];
+        fs::write(&path, bytes).unwrap();
+
+        let mut data = kv_metadata("a-key", path.to_str().unwrap());
+        data.is_file = true;
+        let pair = KeyValuePair::from_kv_metadata(data).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert!(pair.base64);
+        assert_eq!(pair.value, base64::encode(bytes));
+    }
+
+    #[test]
+    fn batch_pairs_skips_entries_whose_file_cannot_be_read() {
+        let mut unreadable = kv_metadata("bad-key", "/no/such/file");
+        unreadable.is_file = true;
+        let ok = kv_metadata("good-key", "a-value");
+
+        let batches = batch_pairs(vec![unreadable, ok]).unwrap();
+
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].len(), 1);
+        assert_eq!(batches[0][0].key, "good-key");
+    }
+
+    #[test]
+    fn metadata_file_parser_reads_and_validates_json() {
+        let path = std::env::temp_dir().join("wrangler_test_metadata_file_parser.json");
+        fs::write(&path, r#"{"key": "value"}"#).unwrap();
+
+        let result = parse_metadata_file(path.to_str().unwrap());
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(result.unwrap(), Some(serde_json::json!({"key": "value"})));
+    }
+
+    #[test]
+    fn check_metadata_source_rejects_a_metadata_path_with_no_parsed_metadata() {
+        let mut data = kv_metadata("a-key", "a-value");
+        data.metadata_path = Some("/some/path.json".to_string());
+        assert!(check_metadata_source(&data).is_err());
+    }
+
+    #[test]
+    fn check_metadata_source_allows_a_metadata_path_with_parsed_metadata() {
+        let mut data = kv_metadata("a-key", "a-value");
+        data.metadata_path = Some("/some/path.json".to_string());
+        data.metadata = Some(serde_json::json!({"key": "value"}));
+        assert!(check_metadata_source(&data).is_ok());
+    }
+
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("wrangler_test_{}", name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn collect_directory_pairs_keys_by_relative_path_and_prefix() {
+        let dir = temp_dir("collect_pairs_prefix");
+        fs::create_dir_all(dir.join("sub")).unwrap();
+        fs::write(dir.join("a.txt"), b"hello").unwrap();
+        fs::write(dir.join("sub/b.txt"), b"world").unwrap();
+
+        let pairs = collect_directory_pairs("namespace-id", &dir, Some("assets"), None).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let mut keys: Vec<&str> = pairs.iter().map(|p| p.key.as_str()).collect();
+        keys.sort();
+        assert_eq!(keys, vec!["assets/a.txt", "assets/sub/b.txt"]);
+    }
+
+    #[test]
+    fn collect_directory_pairs_base64_encodes_file_contents() {
+        let dir = temp_dir("collect_pairs_base64");
+        fs::write(dir.join("a.txt"), b"hello").unwrap();
+
+        let pairs = collect_directory_pairs("namespace-id", &dir, None, None).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(pairs.len(), 1);
+        assert!(pairs[0].base64);
+        assert_eq!(pairs[0].value, base64::encode("hello"));
+    }
+
+    #[test]
+    fn collect_directory_pairs_prefers_sidecar_metadata_over_shared_metadata() {
+        let dir = temp_dir("collect_pairs_sidecar");
+        fs::write(dir.join("a.txt"), b"hello").unwrap();
+        fs::write(dir.join("a.txt.meta.json"), r#"{"from": "sidecar"}"#).unwrap();
+        fs::write(dir.join("b.txt"), b"world").unwrap();
+
+        let shared = serde_json::json!({"from": "shared"});
+        let pairs =
+            collect_directory_pairs("namespace-id", &dir, None, Some(shared.clone())).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        // The sidecar file itself isn't uploaded as a pair of its own.
+        assert_eq!(pairs.len(), 2);
+
+        let a = pairs.iter().find(|p| p.key == "a.txt").unwrap();
+        let b = pairs.iter().find(|p| p.key == "b.txt").unwrap();
+        assert_eq!(a.metadata, Some(serde_json::json!({"from": "sidecar"})));
+        assert_eq!(b.metadata, Some(shared));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn collect_directory_pairs_skips_symlinks() {
+        let dir = temp_dir("collect_pairs_symlink");
+        fs::write(dir.join("a.txt"), b"hello").unwrap();
+        std::os::unix::fs::symlink(dir.join("a.txt"), dir.join("link.txt")).unwrap();
+
+        let pairs = collect_directory_pairs("namespace-id", &dir, None, None).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0].key, "a.txt");
+    }
 }